@@ -11,13 +11,15 @@ use std::{
     fmt,
     fmt::{Display, Formatter},
     fs,
-    io::{Error, ErrorKind},
+    io::{self, Error, ErrorKind},
     path::{self, PathBuf},
     process,
     str::FromStr,
 };
+use globset::GlobBuilder;
+use indicatif::{ProgressBar, ProgressStyle};
 use zip::write::FileOptions;
-use zip::ZipWriter;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
 
 mod utils;
 
@@ -25,6 +27,73 @@ static KB_DEF: &str = "Kb";
 static MB_DEF: &str = "Mb";
 static GB_DEF: &str = "Gb";
 
+/// The compression level used when `-R` doesn't specify one.
+static DEFAULT_COMPRESSION_LEVEL: i32 = 6;
+
+/// Parses a `-R` token (e.g. `zstd:19`, `bzip2`, `store`) into a `CompressionMethod`
+/// and an optional level.
+///
+/// Unknown methods are reported and fall back to Deflate at
+/// `DEFAULT_COMPRESSION_LEVEL`, since a missing archive is worse than a
+/// suboptimal one.
+fn parse_redundancy(redundancy: &str) -> (CompressionMethod, Option<i32>) {
+    if redundancy.is_empty() {
+        return (CompressionMethod::Deflated, Some(DEFAULT_COMPRESSION_LEVEL));
+    }
+
+    let mut parts = redundancy.splitn(2, ':');
+    let method_token = parts.next().unwrap_or("").to_lowercase();
+    let level = parts.next().and_then(|lvl| lvl.parse::<i32>().ok());
+
+    let method = match method_token.as_str() {
+        "store" | "stored" => CompressionMethod::Stored,
+        "deflate" | "deflated" => CompressionMethod::Deflated,
+        "bzip2" => CompressionMethod::Bzip2,
+        "zstd" => CompressionMethod::Zstd,
+        _ => {
+            eprintln!(
+                "[WARN] Unknown compression method '{}', falling back to deflate",
+                method_token
+            );
+            return (CompressionMethod::Deflated, Some(DEFAULT_COMPRESSION_LEVEL));
+        }
+    };
+
+    let level = match method {
+        // Stored is, by definition, not leveled.
+        CompressionMethod::Stored => None,
+        _ => level.or(Some(DEFAULT_COMPRESSION_LEVEL)),
+    };
+
+    (method, level)
+}
+
+/// Converts a relative path into the forward-slash-separated string zip
+/// expects for entry names, regardless of the host's path separator.
+fn to_archive_path(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Extensions treated as compressible "text-like" content when estimating
+/// the archive size in `Cli::how_is_big`; anything else (images, archives,
+/// other already-compressed binaries) is assumed to barely shrink.
+static TEXT_LIKE_EXTENSIONS: &[&str] = &[
+    "txt", "md", "rs", "toml", "json", "yaml", "yml", "xml", "html", "htm", "css", "js", "ts",
+    "jsx", "tsx", "c", "h", "cpp", "hpp", "py", "rb", "go", "java", "sh", "csv", "ini", "cfg",
+    "conf", "log",
+];
+
+/// Returns whether `path`'s extension marks it as compressible text-like
+/// content, used to weight the archive size estimate in `how_is_big`.
+fn is_text_like(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| TEXT_LIKE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
 /// Represents command-line options.
 #[derive(Debug)]
 enum Options {
@@ -36,6 +105,14 @@ enum Options {
     Exclude,
     /// Option to set the level of redundancy.
     Redundancy,
+    /// Option to extract an archive instead of creating one.
+    Extract,
+    /// Option to skip folding `.gitignore` patterns into the exclusion set.
+    NoGitignore,
+    /// Option to preview the archive without writing one.
+    DryRun,
+    /// Option to strip the target's own directory name from archive entries.
+    Flat,
     /// Option to display help information.
     Helper,
 }
@@ -52,10 +129,14 @@ impl FromStr for Options {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             // TODO: Switch -o with -r (target dir and filename)
-            "-o" => Ok(Options::Output),
+            "-o" | "-t" => Ok(Options::Output),
             "-O" => Ok(Options::FileName),
             "-e" => Ok(Options::Exclude),
             "-R" => Ok(Options::Redundancy),
+            "-x" => Ok(Options::Extract),
+            "--no-gitignore" => Ok(Options::NoGitignore),
+            "--dry-run" => Ok(Options::DryRun),
+            "--flat" => Ok(Options::Flat),
             "-h" => Ok(Options::Helper),
             _ => Err(ErrorOptions::NotValidOption),
         }
@@ -70,10 +151,14 @@ impl Display for Options {
             f,
             "{}",
             match self {
-                Options::Output => "-o",
+                Options::Output => "-t",
                 Options::FileName => "-O",
                 Options::Exclude => "-e",
                 Options::Redundancy => "-R",
+                Options::Extract => "-x",
+                Options::NoGitignore => "--no-gitignore",
+                Options::DryRun => "--dry-run",
+                Options::Flat => "--flat",
                 Options::Helper => "-h",
             }
         )
@@ -94,6 +179,15 @@ struct CliArgs {
     redundancy: String,
     // Things you're leaving behind (like pineapple pizza).
     excluded: String,
+    // Whether to ignore the target's .gitignore when building exclusions.
+    no_gitignore: bool,
+    // Whether to only preview the archive instead of writing one.
+    dry_run: bool,
+    // The archive to extract, if we're unzipping instead of zipping.
+    extract: String,
+    // Whether to strip the target's own directory name from archive
+    // entries instead of keeping it as the archive root.
+    flat: bool,
 }
 
 impl CliArgs {
@@ -104,6 +198,10 @@ impl CliArgs {
             target: "".to_string(),
             redundancy: "".to_string(),
             excluded: "".to_string(),
+            no_gitignore: false,
+            dry_run: false,
+            extract: "".to_string(),
+            flat: false,
         }
     }
 }
@@ -112,8 +210,14 @@ impl Display for CliArgs {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "Target: {}, Redundancy: {}, Excluded: {}",
-            self.target, self.redundancy, self.excluded
+            "Target: {}, Redundancy: {}, Excluded: {}, NoGitignore: {}, DryRun: {}, Extract: {}, Flat: {}",
+            self.target,
+            self.redundancy,
+            self.excluded,
+            self.no_gitignore,
+            self.dry_run,
+            self.extract,
+            self.flat
         )
     }
 }
@@ -128,6 +232,63 @@ enum FileType {
     File, // A single piece of paper in that drawer.
 }
 
+/// A single compiled exclusion rule, either from `-e` or a `.gitignore` line.
+///
+/// Mirrors git's own semantics: rules are evaluated in order and the last
+/// match wins, so a `!pattern` can re-include something an earlier pattern
+/// excluded.
+struct ExcludeRule {
+    glob: globset::GlobMatcher,
+    negate: bool,
+    dir_only: bool,
+    // Anchored rules (leading `/`) match the path relative to the target only.
+    anchored: bool,
+}
+
+impl ExcludeRule {
+    /// Compiles a single glob pattern (as found in `-e` or a `.gitignore`
+    /// line) into an `ExcludeRule`, honoring the leading `!` negation, the
+    /// trailing `/` directory-only marker and the leading `/` anchor.
+    fn compile(pattern: &str) -> Option<Self> {
+        let (negate, pattern) = match pattern.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, pattern),
+        };
+        let (dir_only, pattern) = match pattern.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, pattern),
+        };
+        // A leading slash anchors the pattern to the target root, the same
+        // way gitignore does (e.g. `/target`); strip it so the glob matches
+        // the relative path directly instead of literally starting with `/`.
+        let (anchored, pattern) = match pattern.strip_prefix('/') {
+            Some(rest) => (true, rest),
+            None => (false, pattern),
+        };
+
+        // A bare, non-anchored name like `target` should match at any
+        // depth, the same way git matches a pattern with no slash in it.
+        let pattern = if anchored || pattern.contains('/') {
+            pattern.to_string()
+        } else {
+            format!("**/{}", pattern)
+        };
+
+        // An anchored pattern's `*` must not cross `/`, or `/*.log` would
+        // still match `src/app.log`.
+        let glob = GlobBuilder::new(&pattern)
+            .literal_separator(anchored)
+            .build()
+            .ok()?;
+        Some(Self {
+            glob: glob.compile_matcher(),
+            negate,
+            dir_only,
+            anchored,
+        })
+    }
+}
+
 /// A struct representing the command-line interface.
 ///
 /// Think of it as the control panel of your spaceship.
@@ -167,31 +328,212 @@ impl Cli {
         }
     }
 
-    /// Prepares directories for... well, something important. (To be implemented)
-    fn prepare_exclude_directories(&self) -> Vec<PathBuf> {
-        let exclude_dirs: Vec<&str> = self.arguments.excluded.split(",").collect();
-        let exclude_dirs_buf: Vec<PathBuf> = exclude_dirs.iter().map(|path| PathBuf::from(path)).collect();
+    /// Builds the exclusion rule set: the comma-separated `-e` globs, folded
+    /// together with the target's `.gitignore` patterns (unless
+    /// `--no-gitignore` was given).
+    fn prepare_exclude_directories(&self) -> Vec<ExcludeRule> {
+        let mut rules: Vec<ExcludeRule> = self
+            .arguments
+            .excluded
+            .split(',')
+            .map(str::trim)
+            .filter(|pattern| !pattern.is_empty())
+            .filter_map(ExcludeRule::compile)
+            .collect();
+
+        rules.extend(self.parse_gitignore_patterns());
+        rules
+    }
+
+    /// Reads and compiles the target's `.gitignore`, if any, into exclusion
+    /// rules. Returns an empty list when `--no-gitignore` is set or the file
+    /// doesn't exist.
+    fn parse_gitignore_patterns(&self) -> Vec<ExcludeRule> {
+        if self.arguments.no_gitignore {
+            return Vec::new();
+        }
+
+        let gitignore_path = Path::new(&self.arguments.target).join(".gitignore");
+        let Ok(contents) = fs::read_to_string(&gitignore_path) else {
+            return Vec::new();
+        };
+
+        contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(ExcludeRule::compile)
+            .collect()
+    }
+
+    /// Predicts how big the zip file will be. The per-method compression
+    /// ratio is applied only to the text-like portion of `total_size`
+    /// (source, docs, config — see `is_text_like`); everything else
+    /// (images, already-compressed assets) is charged its full size, since
+    /// it won't meaningfully shrink. (It's like fortune-telling for files)
+    fn how_is_big(&self, total_size: u64) -> Result<(f64, &str), Error> {
+        let (method, _) = parse_redundancy(&self.arguments.redundancy);
+
+        // Rough ratios for text-heavy source trees; real ratios vary a lot
+        // with content, but this gives the user a ballpark before zipping.
+        let ratio = match method {
+            CompressionMethod::Stored => 1.0,
+            CompressionMethod::Deflated => 0.4,
+            CompressionMethod::Bzip2 => 0.35,
+            CompressionMethod::Zstd => 0.3,
+            _ => 0.5,
+        };
+
+        let rules = self.prepare_exclude_directories();
+        let text_like_size =
+            self.calculate_text_like_size(Path::new(&self.arguments.target), &rules)?;
+        let binary_size = total_size.saturating_sub(text_like_size);
+
+        let estimated = (text_like_size as f64 * ratio) + binary_size as f64;
+        Ok(self.as_human_read(estimated as u64))
+    }
+
+    /// Recursively sums the size of everything under `path`, skipping
+    /// entries matched by `rules`. Shares the stack-based walk used by
+    /// `calculate_target_size`, but scoped to a single subtree.
+    fn calculate_subtree_size(&self, path: &Path, rules: &[ExcludeRule]) -> Result<u64, Error> {
+        let mut stack: Vec<PathBuf> = vec![path.to_path_buf()];
+        let mut total_size = 0;
+
+        while let Some(current_path) = stack.pop() {
+            if current_path.is_dir() {
+                for entry in fs::read_dir(&current_path)? {
+                    let entry = entry?;
+                    if !self.is_excluded_path(&entry, rules) {
+                        stack.push(entry.path());
+                    }
+                }
+            } else {
+                total_size += fs::metadata(&current_path)?.len();
+            }
+        }
+
+        Ok(total_size)
+    }
+
+    /// Recursively sums the size of text-like entries (see `is_text_like`)
+    /// under `path`, skipping entries matched by `rules`. Shares the same
+    /// walk as `calculate_subtree_size`, but only counts compressible
+    /// content.
+    fn calculate_text_like_size(&self, path: &Path, rules: &[ExcludeRule]) -> Result<u64, Error> {
+        let mut stack: Vec<PathBuf> = vec![path.to_path_buf()];
+        let mut total_size = 0;
+
+        while let Some(current_path) = stack.pop() {
+            if current_path.is_dir() {
+                for entry in fs::read_dir(&current_path)? {
+                    let entry = entry?;
+                    if !self.is_excluded_path(&entry, rules) {
+                        stack.push(entry.path());
+                    }
+                }
+            } else if is_text_like(&current_path) {
+                total_size += fs::metadata(&current_path)?.len();
+            }
+        }
+
+        Ok(total_size)
+    }
+
+    /// Sums the size of each immediate child of `self.arguments.target`,
+    /// skipping excluded entries, and sorts the result descending by size —
+    /// the per-entry breakdown a dutree-style report ranks.
+    fn calculate_child_sizes(&self) -> Result<Vec<(PathBuf, u64)>, Error> {
+        let rules = self.prepare_exclude_directories();
+        let target = Path::new(&self.arguments.target);
+        let mut sizes = Vec::new();
+
+        for entry in fs::read_dir(target)? {
+            let entry = entry?;
+            if self.is_excluded_path(&entry, &rules) {
+                continue;
+            }
+            let size = self.calculate_subtree_size(&entry.path(), &rules)?;
+            sizes.push((entry.path(), size));
+        }
 
-        return exclude_dirs_buf;
+        sizes.sort_by_key(|(_, size)| std::cmp::Reverse(*size));
+        Ok(sizes)
     }
 
-    /// Predicts how big the zip file will be. (It's like fortune-telling for files)
-    fn how_is_big(&self) {
-        /* TODO: To implement */
-        // From the array of directories got in input (i.e. directories: Vec<Something>)
-        // predict the size of the zip file (No fucking idea how to do this yet)
-        // return the predicted size of the zip file
-        todo!("Predict the zip file size")
+    /// Renders a dutree-style ranked breakdown: each entry's human-readable
+    /// size plus a proportional bar showing its share of `total`.
+    fn render_size_tree(&self, sizes: &[(PathBuf, u64)], total: u64) {
+        const BAR_WIDTH: usize = 30;
+
+        for (path, size) in sizes {
+            let (value, unit) = self.as_human_read(*size);
+            let share = if total == 0 { 0.0 } else { *size as f64 / total as f64 };
+            let filled = (share * BAR_WIDTH as f64).round() as usize;
+            let bar = format!("{}{}", "#".repeat(filled), "-".repeat(BAR_WIDTH - filled));
+
+            println!(
+                "[{bar}] {value:>8.2}{unit} {:>6.2}%  {}",
+                share * 100.0,
+                path.display()
+            );
+        }
     }
 
-    /// Prints a preview of what's going to happen. (Everyone loves a sneak peek)
+    /// Prints a dutree-style preview of what `zip_files` would archive: the
+    /// ranked per-entry sizes, the total, and the estimated archive size.
+    /// Runs before `zip_files`, and can also be requested standalone with
+    /// `--dry-run`.
     fn print_preview(&self) {
-        /* TODO: To implement */
-        // Get all the details about:
-        // - the zip file size
-        // - the directory zip
-        // - the directories to avoid
-        todo!("Print the preview before continue the program")
+        let sizes = match self.calculate_child_sizes() {
+            Ok(sizes) => sizes,
+            Err(er) => {
+                eprintln!("[EROR] {}", er);
+                return;
+            }
+        };
+
+        let total: u64 = sizes.iter().map(|(_, size)| *size).sum();
+        let (total_value, total_unit) = self.as_human_read(total);
+        let (estimated_value, estimated_unit) = match self.how_is_big(total) {
+            Ok(estimate) => estimate,
+            Err(er) => {
+                eprintln!("[EROR] {}", er);
+                return;
+            }
+        };
+
+        println!("Preview for {}", self.arguments.target);
+        self.render_size_tree(&sizes, total);
+        println!("Total: {:.2}{}", total_value, total_unit);
+        println!(
+            "Estimated archive size: {:.2}{}",
+            estimated_value, estimated_unit
+        );
+    }
+
+    /// Computes the path prefix to strip from archive entry names: by
+    /// default the target's parent, so the target's own directory name
+    /// becomes the archive root (`cdc -t ~/projects/myapp` yields
+    /// `myapp/...` entries); with `--flat`, the target itself, for the
+    /// old behaviour of dumping entries at the archive root.
+    fn strip_base(&self) -> PathBuf {
+        let target = Path::new(&self.arguments.target);
+        if self.arguments.flat {
+            target.to_path_buf()
+        } else {
+            target.parent().map(Path::to_path_buf).unwrap_or_default()
+        }
+    }
+
+    /// Builds the `FileOptions` to use for every entry written to the zip,
+    /// derived from the `-R` redundancy argument.
+    fn configure_compression(&self) -> FileOptions {
+        let (method, level) = parse_redundancy(&self.arguments.redundancy);
+
+        FileOptions::default()
+            .compression_method(method)
+            .compression_level(level)
     }
 
     fn configure_output_name(&self) -> &Path {
@@ -203,15 +545,53 @@ impl Cli {
         return Path::new(&self.arguments.filename_out);
     }
 
-    fn is_excluded_path(&self, path: &DirEntry) -> bool {
-        // FIXME: parse the excluded path names correctly
-        let exclude_paths: Vec<PathBuf> = self.prepare_exclude_directories();
+    /// Tests a directory entry against the exclusion rules, matching both
+    /// its path relative to `self.arguments.target` and its bare file name.
+    /// Anchored rules (leading `/`) are matched against the relative path only.
+    fn is_excluded_path(&self, path: &DirEntry, rules: &[ExcludeRule]) -> bool {
+        let entry_path = path.path();
+        let relative_path = entry_path
+            .strip_prefix(&self.arguments.target)
+            .unwrap_or(&entry_path);
+        let file_name = path.file_name();
+        let is_dir = entry_path.is_dir();
+
+        let mut excluded = false;
+        for rule in rules {
+            if rule.dir_only && !is_dir {
+                continue;
+            }
+            if rule.glob.is_match(relative_path)
+                || (!rule.anchored && rule.glob.is_match(Path::new(&file_name)))
+            {
+                excluded = !rule.negate;
+            }
+        }
+
+        excluded
+    }
+
+    /// Builds a progress bar sized to `total_size`, or `None` when stdout
+    /// isn't a TTY so piped output stays clean.
+    fn build_progress_bar(&self, total_size: u64) -> Option<ProgressBar> {
+        if !atty::is(atty::Stream::Stdout) {
+            return None;
+        }
+
+        let pb = ProgressBar::new(total_size);
+        pb.set_style(
+            ProgressStyle::with_template(
+                "{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({bytes_per_sec}, ETA {eta}) {msg}",
+            )
+            .unwrap()
+            .progress_chars("#>-"),
+        );
 
-        return exclude_paths.contains(&path.path());
+        Some(pb)
     }
 
     /// Zip the target directory excluding the choosen ones
-    fn zip_files(&self) -> Result<(), Error> {
+    fn zip_files(&self, total_size: u64) -> Result<(), Error> {
         // TODO: Refactor this function
         // TODO: Set the zip file name using cli argument
         let path = self.configure_output_name();
@@ -220,30 +600,34 @@ impl Cli {
         let mut stack: Vec<PathBuf> = vec![PathBuf::from(&self.arguments.target)];
         let mut zip = ZipWriter::new(file);
         let mut file_counter: usize = 0;
+        let options = self.configure_compression();
+        let progress = self.build_progress_bar(total_size);
+        let exclude_rules = self.prepare_exclude_directories();
+        let strip_base = self.strip_base();
 
         while let Some(current_path) = stack.pop() {
             let relative_path = current_path
-                .strip_prefix(&self.arguments.target)
+                .strip_prefix(&strip_base)
                 .unwrap_or(&current_path);
 
             if current_path.is_dir() {
                 for entry in fs::read_dir(&current_path)? {
                     let entry = entry?;
                     // if entry is not an excluded
-                    if !self.is_excluded_path(&entry) {
+                    if !self.is_excluded_path(&entry, &exclude_rules) {
                         stack.push(entry.path());
                     }
                     continue;
                 }
                 if relative_path != Path::new("") {
-                    let mut dir_path = relative_path.to_str().unwrap().to_owned();
+                    let mut dir_path = to_archive_path(relative_path);
                     dir_path.push('/'); // add a '/' to the end of the path
 
-                    zip.add_directory(&dir_path, FileOptions::default())?;
+                    zip.add_directory(&dir_path, options)?;
                     println!("Directory {} added to zip file!", dir_path);
                 }
             } else {
-                zip.start_file(relative_path.to_str().unwrap(), FileOptions::default())?;
+                zip.start_file(to_archive_path(relative_path), options)?;
                 let mut f = File::open(&current_path)?;
 
                 let mut buffer = Vec::new();
@@ -251,10 +635,20 @@ impl Cli {
                 zip.write_all(&buffer)?;
 
                 file_counter += 1;
-                println!("File {:?} zipped!", current_path.file_name().unwrap());
+                match &progress {
+                    Some(pb) => {
+                        pb.set_message(current_path.file_name().unwrap().to_string_lossy().into_owned());
+                        pb.inc(buffer.len() as u64);
+                    }
+                    None => println!("File {:?} zipped!", current_path.file_name().unwrap()),
+                }
             }
         }
 
+        if let Some(pb) = &progress {
+            pb.finish_with_message("done");
+        }
+
         zip.finish()?;
         println!("Zip file created successfully!");
         println!("Total files zipped: {}", file_counter);
@@ -262,26 +656,72 @@ impl Cli {
         Ok(())
     }
 
-    /// Calculates the total size of the target directory.
+    /// Extracts `self.arguments.extract` into `self.arguments.target`,
+    /// the inverse of `zip_files`.
     ///
-    /// It's like weighing your suitcase before a flight to avoid extra fees.
-    fn calculate_target_size(&self) -> Result<u64, Error> {
-        let path = path::Path::new(self.arguments.target.as_str());
-        let mut total_size = 0;
-        let mut stack: Vec<PathBuf> = vec![path.to_path_buf()];
+    /// Guards against zip-slip: each entry is resolved through
+    /// `ZipFile::enclosed_name`, which refuses `..` components and absolute
+    /// paths, so a malicious archive can't write outside the output
+    /// directory.
+    fn extract_files(&self) -> Result<(), Error> {
+        let archive_path = Path::new(&self.arguments.extract);
+        let file = File::open(archive_path)?;
+        let mut archive =
+            ZipArchive::new(file).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+
+        let output_dir = Path::new(&self.arguments.target);
+        fs::create_dir_all(output_dir)?;
 
-        while let Some(current_path) = stack.pop() {
-            if current_path.is_dir() {
-                for entry in fs::read_dir(&current_path)? {
-                    let entry = entry?;
-                    let entry_path = entry.path();
-                    stack.push(entry_path);
-                }
-            } else {
-                total_size += fs::metadata(&current_path)?.len();
+        let mut file_counter: usize = 0;
+
+        for i in 0..archive.len() {
+            let mut entry = archive
+                .by_index(i)
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+
+            let Some(enclosed_name) = entry.enclosed_name() else {
+                eprintln!("[WARN] Skipping unsafe entry path: {}", entry.name());
+                continue;
+            };
+            let out_path = output_dir.join(enclosed_name);
+
+            if entry.is_dir() {
+                fs::create_dir_all(&out_path)?;
+                continue;
+            }
+
+            if let Some(parent) = out_path.parent() {
+                fs::create_dir_all(parent)?;
             }
+
+            let mut out_file = File::create(&out_path)?;
+            io::copy(&mut entry, &mut out_file)?;
+
+            #[cfg(unix)]
+            if let Some(mode) = entry.unix_mode() {
+                use std::os::unix::fs::PermissionsExt;
+                fs::set_permissions(&out_path, fs::Permissions::from_mode(mode))?;
+            }
+
+            file_counter += 1;
+            println!("File {:?} extracted!", out_path.file_name().unwrap());
         }
-        return Ok(total_size);
+
+        println!("Archive extracted successfully!");
+        println!("Total files extracted: {}", file_counter);
+
+        Ok(())
+    }
+
+    /// Calculates the total size of the target directory, skipping anything
+    /// matched by the exclusion rules — the same walk `zip_files` performs,
+    /// so the precomputed total (and the progress bar sized from it)
+    /// matches what actually gets written.
+    ///
+    /// It's like weighing your suitcase before a flight to avoid extra fees.
+    fn calculate_target_size(&self) -> Result<u64, Error> {
+        let rules = self.prepare_exclude_directories();
+        self.calculate_subtree_size(path::Path::new(self.arguments.target.as_str()), &rules)
     }
 
     /// Runs the tool and zips the files.
@@ -290,11 +730,23 @@ impl Cli {
             .unwrap_or_else(|er| eprintln!("[EROR] {}", er));
         println!("[INFO] parsed: {:#?}", self);
 
+        if !self.arguments.extract.is_empty() {
+            self.extract_files()
+                .unwrap_or_else(|er| eprintln!("[EROR] {}", er));
+            return;
+        }
+
+        self.print_preview();
+
+        if self.arguments.dry_run {
+            return;
+        }
+
         match self.calculate_target_size() {
             Ok(size) => {
                 let (s, t) = self.as_human_read(size);
                 println!("{}{}", s as usize, t);
-                self.zip_files()
+                self.zip_files(size)
                     .unwrap_or_else(|er| eprintln!("[EROR] {}", er));
             }
             Err(er) => eprintln!("[EROR] {}", er),
@@ -326,6 +778,18 @@ fn main() {
             Some(Options::Redundancy) => {
                 cli_config.redundancy = args[i + 1].clone();
             }
+            Some(Options::Extract) => {
+                cli_config.extract = args[i + 1].clone();
+            }
+            Some(Options::NoGitignore) => {
+                cli_config.no_gitignore = true;
+            }
+            Some(Options::DryRun) => {
+                cli_config.dry_run = true;
+            }
+            Some(Options::Flat) => {
+                cli_config.flat = true;
+            }
             Some(Options::Helper) => {
                 utils::print_helper(None);
                 process::exit(1);
@@ -27,10 +27,15 @@ pub fn print_helper(support_message: Option<String>) {
 Your best Code directory cleaner
 
 Options:
-  -t          Set the target directory
-  -O          Set the output filename
-  -e          Exlude directories
-  -h          Print the helps
+  -t               Set the target directory
+  -O               Set the output filename
+  -e               Exlude directories (comma-separated globs)
+  -R               Set the compression method/level (e.g. zstd:19, store)
+  -x               Extract an archive into the target directory (-t)
+  --no-gitignore   Don't fold the target's .gitignore into the exclusions
+  --dry-run        Preview the archive without writing one
+  --flat           Strip the target's own directory name from archive entries
+  -h               Print the helps
     "#;
     println!("{}", help);
 }